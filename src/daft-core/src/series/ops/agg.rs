@@ -13,6 +13,12 @@ use crate::{
     with_match_physical_daft_types,
 };
 
+/// log2 of the number of HLL registers; 2^14 registers gives ~0.8% standard error.
+const HLL_PRECISION: u32 = 14;
+const NUM_REGISTERS: usize = 1 << HLL_PRECISION;
+/// Bias-correction constant for `m = NUM_REGISTERS` registers.
+const ALPHA_M: f64 = 0.7213 / (1.0 + 1.079 / NUM_REGISTERS as f64);
+
 impl Series {
     pub fn count(&self, groups: Option<&GroupIndices>, mode: CountMode) -> DaftResult<Series> {
         use crate::array::ops::DaftCountAggable;
@@ -148,6 +154,76 @@ impl Series {
         Ok(series)
     }
 
+    pub fn hll_sketch(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::as_arrow::AsArrow;
+
+        // Standard HLL layout: `NUM_REGISTERS` single-byte registers, indexed by
+        // the low `HLL_PRECISION` bits of each element's hash. Each register holds
+        // the max trailing-zero-count (+1) observed over the remaining hash bits.
+        let hashed = self.hash(None)?;
+        let hashes = hashed.u64()?.as_arrow();
+
+        let registers_for = |hashes_iter: &mut dyn Iterator<Item = u64>| {
+            let mut registers = vec![0u8; NUM_REGISTERS];
+            for hash in hashes_iter {
+                let index = (hash & (NUM_REGISTERS as u64 - 1)) as usize;
+                let rest = hash >> HLL_PRECISION;
+                let leading = (rest | (1 << (64 - HLL_PRECISION))).trailing_zeros() as u8 + 1;
+                registers[index] = registers[index].max(leading);
+            }
+            registers
+        };
+
+        // One registers blob per group, so `grouped_hll_merge` (which expects
+        // exactly one sketch per input row) can bucket them the same way
+        // `grouped_approx_sketch` does.
+        let blobs: Vec<Option<Vec<u8>>> = match groups {
+            Some(groups) => groups
+                .iter()
+                .map(|group| {
+                    Some(registers_for(
+                        &mut group.iter().map(|&idx| hashes.value(idx as usize)),
+                    ))
+                })
+                .collect(),
+            None => vec![Some(registers_for(&mut hashes.values_iter().copied()))],
+        };
+
+        let array = FixedSizeBinaryArray::from_iter(self.name(), blobs, NUM_REGISTERS);
+        Ok(array.into_series())
+    }
+
+    pub fn hll_estimate(&self) -> DaftResult<Series> {
+        use crate::array::ops::as_arrow::AsArrow;
+
+        let registers = self.downcast::<FixedSizeBinaryArray>()?.as_arrow();
+        let estimates = registers.iter().map(|maybe_blob| {
+            maybe_blob.map(|blob| {
+                let m = blob.len() as f64;
+                let mut sum = 0.0;
+                let mut zeros = 0u64;
+                for &r in blob {
+                    sum += 2f64.powi(-(r as i32));
+                    if r == 0 {
+                        zeros += 1;
+                    }
+                }
+                let estimate = ALPHA_M * m * m / sum;
+                // Small-range correction via linear counting.
+                if estimate <= 2.5 * m && zeros > 0 {
+                    (m * (m / zeros as f64).ln()).round() as u64
+                } else {
+                    estimate.round() as u64
+                }
+            })
+        });
+        let array = UInt64Array::from_iter(
+            Field::new(self.name(), DataType::UInt64),
+            estimates,
+        );
+        Ok(array.into_series())
+    }
+
     pub fn mean(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         use crate::{array::ops::DaftMeanAggable, datatypes::DataType::*};
 
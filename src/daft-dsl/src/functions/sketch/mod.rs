@@ -0,0 +1,44 @@
+mod percentile;
+
+use percentile::PercentileEvaluator;
+use serde::{Deserialize, Serialize};
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SketchExpr {
+    /// Estimate the value at quantile `q` (in `[0, 1]`) from a column of
+    /// bincode-encoded `Sketch`es -- see `percentile::Sketch`.
+    Percentile { q: f64 },
+}
+
+// `f64` has no total order (NaN), so `Eq`/`Hash` can't be derived; compare and
+// hash on the bit pattern instead, the same trick `asof_join`'s group keys use.
+impl PartialEq for SketchExpr {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Percentile { q: a }, Self::Percentile { q: b }) => a.to_bits() == b.to_bits(),
+        }
+    }
+}
+
+impl Eq for SketchExpr {}
+
+impl std::hash::Hash for SketchExpr {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Percentile { q } => q.to_bits().hash(state),
+        }
+    }
+}
+
+impl SketchExpr {
+    #[inline]
+    pub(super) fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use SketchExpr::*;
+        static PERCENTILE: PercentileEvaluator = PercentileEvaluator {};
+        match self {
+            Percentile { .. } => &PERCENTILE,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::super::FunctionEvaluator;
+use super::SketchExpr;
+use crate::{functions::FunctionExpr, ExprRef};
+
+/// A merged DDSketch: a relative-error quantile sketch that maps each value to a
+/// bucket `i = ceil(log(v) / log(gamma))` with `gamma = (1 + alpha) / (1 - alpha)`
+/// for a configured relative accuracy `alpha`. Positive and negative values are
+/// tracked in mirrored bucket stores; zeros are counted separately.
+///
+/// `sketch_percentile` expects one bincode-encoded `Sketch` per row of the input
+/// binary column. `Series::approx_sketch`/`merge_sketch` produce a `Struct`
+/// series, not this layout; this decodes whatever bytes the caller hands it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sketch {
+    pub gamma: f64,
+    pub zero_count: u64,
+    /// Bucket index -> count, for strictly positive values.
+    pub positive: Vec<(i32, u64)>,
+    /// Bucket index -> count, for strictly negative values.
+    pub negative: Vec<(i32, u64)>,
+}
+
+impl Sketch {
+    /// Decode a merged sketch from the serialized blob stored in the aggregation's
+    /// binary sketch column.
+    pub fn from_bytes(bytes: &[u8]) -> DaftResult<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|e| DaftError::ValueError(format!("malformed sketch state: {e}")))
+    }
+
+    fn total_count(&self) -> u64 {
+        self.zero_count
+            + self.positive.iter().map(|(_, c)| c).sum::<u64>()
+            + self.negative.iter().map(|(_, c)| c).sum::<u64>()
+    }
+
+    /// Estimate the value at quantile `q` in `[0, 1]`. Returns `None` for an
+    /// empty sketch.
+    fn quantile(&self, q: f64) -> Option<f64> {
+        let total = self.total_count();
+        if total == 0 {
+            return None;
+        }
+
+        // Rank of the target element, scanning from the most negative bucket up
+        // through zero and into the positive buckets in increasing value order.
+        let rank = (q * total as f64).round() as u64;
+        let mut seen = 0u64;
+
+        // A larger negative-bucket index means a larger magnitude and so a
+        // smaller value: the smallest value is the largest index.
+        let mut negative = self.negative.clone();
+        negative.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        for (i, count) in negative {
+            seen += count;
+            if seen >= rank {
+                return Some(-estimate(self.gamma, i));
+            }
+        }
+
+        seen += self.zero_count;
+        if seen >= rank && self.zero_count > 0 {
+            return Some(0.0);
+        }
+
+        let mut positive = self.positive.clone();
+        positive.sort_unstable_by_key(|(i, _)| *i);
+        for &(i, count) in &positive {
+            seen += count;
+            if seen >= rank {
+                return Some(estimate(self.gamma, i));
+            }
+        }
+
+        // `rank` lands past the last bucket only through rounding; clamp to the max.
+        positive
+            .last()
+            .map(|(i, _)| estimate(self.gamma, *i))
+            .or_else(|| Some(0.0))
+    }
+}
+
+/// The representative value of bucket `i`: the interpolated midpoint
+/// `2 * gamma^i / (gamma + 1)`.
+fn estimate(gamma: f64, i: i32) -> f64 {
+    2.0 * gamma.powi(i) / (gamma + 1.0)
+}
+
+/// Read an estimated quantile out of a binary column of merged sketches. Each
+/// row holds one serialized [`Sketch`]; the result is a `Float64` column aligned to the input, and an
+/// empty or null sketch estimates to null.
+///
+/// Callers asking for several quantiles at once call this once per quantile and
+/// assemble the results, rather than this function emitting a list column
+/// itself; that keeps the finalizer itself a plain per-row map.
+pub fn sketch_percentile(sketch: &Series, q: f64) -> DaftResult<Series> {
+    if !(0.0..=1.0).contains(&q) {
+        return Err(DaftError::ValueError(format!(
+            "sketch_percentile expects a quantile in [0, 1], got {q}"
+        )));
+    }
+    let blobs = sketch.binary()?;
+    let estimates = blobs
+        .into_iter()
+        .map(|blob| match blob {
+            Some(bytes) => {
+                let sketch = Sketch::from_bytes(bytes)?;
+                Ok(sketch.quantile(q))
+            }
+            None => Ok(None),
+        })
+        .collect::<DaftResult<Vec<Option<f64>>>>()?;
+    Series::from_f64_opt_slice(sketch.name(), &estimates)
+}
+
+pub(super) struct PercentileEvaluator {}
+
+impl FunctionEvaluator for PercentileEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "sketch_percentile"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        let q = percentile_arg(expr)?;
+        if !(0.0..=1.0).contains(&q) {
+            return Err(DaftError::ValueError(format!(
+                "sketch_percentile expects a quantile in [0, 1], got {q}"
+            )));
+        }
+        match inputs {
+            [input] => match input.to_field(schema) {
+                Ok(field) if field.dtype == DataType::Binary => {
+                    Ok(Field::new(field.name, DataType::Float64))
+                }
+                Ok(field) => Err(DaftError::TypeError(format!(
+                    "Expected input to sketch_percentile to be a binary sketch column, got {}",
+                    field.dtype
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        let q = percentile_arg(expr)?;
+        match inputs {
+            [sketch] => sketch_percentile(sketch, q),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+fn percentile_arg(expr: &FunctionExpr) -> DaftResult<f64> {
+    match expr {
+        FunctionExpr::Sketch(SketchExpr::Percentile { q }) => Ok(*q),
+        _ => Err(DaftError::ValueError(
+            "Expected sketch_percentile function expression".to_string(),
+        )),
+    }
+}
@@ -0,0 +1,78 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+
+use super::super::FunctionEvaluator;
+use super::TemporalExpr;
+use crate::{functions::FunctionExpr, ExprRef};
+
+pub(super) struct DateBinEvaluator {}
+
+impl FunctionEvaluator for DateBinEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "date_bin"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        let (stride, _) = date_bin_args(expr)?;
+        if stride <= 0 {
+            return Err(DaftError::ValueError(format!(
+                "date_bin stride must be positive, got {stride}"
+            )));
+        }
+        match inputs {
+            [input] => match input.to_field(schema) {
+                // The floored timestamps keep the input's resolution and timezone.
+                Ok(field) if field.dtype.is_temporal() => Ok(field),
+                Ok(field) => Err(DaftError::TypeError(format!(
+                    "Expected input to date_bin to be temporal, got {}",
+                    field.dtype
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        let (stride, origin) = date_bin_args(expr)?;
+        match inputs {
+            [input] => date_bin(input, stride, origin),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+fn date_bin_args(expr: &FunctionExpr) -> DaftResult<(i64, i64)> {
+    match expr {
+        FunctionExpr::Temporal(TemporalExpr::DateBin { stride, origin }) => Ok((*stride, *origin)),
+        _ => Err(DaftError::ValueError(
+            "Expected date_bin function expression".to_string(),
+        )),
+    }
+}
+
+/// Floor each timestamp to the start of its `stride`-wide bucket:
+/// `origin + floor((ts - origin) / stride) * stride`, computed on the
+/// physical `i64` tick values (same unit as `origin`/`stride`) and cast back
+/// to the input's own temporal type so resolution and timezone round-trip.
+fn date_bin(input: &Series, stride: i64, origin: i64) -> DaftResult<Series> {
+    let dtype = input.data_type().clone();
+    let ticks = input.cast(&DataType::Int64)?;
+    let floored = ticks.i64()?.into_iter().map(|tick| {
+        tick.copied()
+            .map(|tick| origin + (tick - origin).div_euclid(stride) * stride)
+    });
+    let array = Int64Array::from_iter(Field::new(input.name(), DataType::Int64), floored);
+    array.into_series().cast(&dtype)
+}
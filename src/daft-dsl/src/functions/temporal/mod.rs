@@ -0,0 +1,36 @@
+mod date_bin;
+mod hour;
+mod year;
+
+use date_bin::DateBinEvaluator;
+use hour::HourEvaluator;
+use serde::{Deserialize, Serialize};
+use year::YearEvaluator;
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum TemporalExpr {
+    Hour,
+    Year,
+    /// Floor each timestamp to the start of its `stride`-wide bucket, measured
+    /// from `origin`: `origin + floor((ts - origin) / stride) * stride`. Both
+    /// are in the input's own time unit, so a microsecond column buckets in
+    /// microseconds.
+    DateBin { stride: i64, origin: i64 },
+}
+
+impl TemporalExpr {
+    #[inline]
+    pub(super) fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use TemporalExpr::*;
+        static HOUR: HourEvaluator = HourEvaluator {};
+        static YEAR: YearEvaluator = YearEvaluator {};
+        static DATE_BIN: DateBinEvaluator = DateBinEvaluator {};
+        match self {
+            Hour => &HOUR,
+            Year => &YEAR,
+            DateBin { .. } => &DATE_BIN,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use common_treenode::{Transformed, TreeNode};
+use daft_dsl::functions::FunctionExpr;
+use daft_dsl::{col, Expr, ExprRef};
+
+// Common-subexpression elimination. Any expression subtree that appears more than
+// once and is non-trivial to compute is hoisted into a synthetic column evaluated
+// once, and the duplicated subtrees are replaced with references to that column.
+// `physical_plan_to_pipeline` runs it as a pre-pass while lowering each `Project`,
+// injecting the upstream `ProjectOperator` that materializes the hoisted columns.
+
+/// Count structurally identical subexpressions across `exprs`, keyed by a
+/// structural hash of each subtree.
+fn count_subexpressions(exprs: &[ExprRef]) -> HashMap<u64, (ExprRef, usize)> {
+    let mut counts: HashMap<u64, (ExprRef, usize)> = HashMap::new();
+    for expr in exprs {
+        let _ = expr.apply(&mut |sub: &ExprRef| {
+            let key = structural_hash(sub);
+            counts
+                .entry(key)
+                .and_modify(|(_, c)| *c += 1)
+                .or_insert_with(|| (sub.clone(), 1));
+            Ok(common_treenode::TreeNodeRecursion::Continue)
+        });
+    }
+    counts
+}
+
+/// Rewrite `exprs`, hoisting every duplicated non-trivial subtree into a named
+/// column. Returns the rewritten expressions plus the projection expressions that
+/// must be computed upstream to materialize the hoisted columns.
+pub fn eliminate_common_subexpressions(exprs: &[ExprRef]) -> (Vec<ExprRef>, Vec<ExprRef>) {
+    let counts = count_subexpressions(exprs);
+    // Keyed by the structural hash, but each entry keeps the canonical `ExprRef`
+    // it was built from so a hash collision can't substitute the wrong subtree:
+    // a hit only applies once `sub` is checked for real structural equality
+    // against that canonical expression.
+    let hoisted: HashMap<u64, (ExprRef, String)> = counts
+        .iter()
+        .filter(|(_, (expr, count))| *count > 1 && !is_trivial(expr) && !is_volatile(expr))
+        .map(|(&key, (expr, _))| (key, (expr.clone(), format!("__cse_{key:x}"))))
+        .collect();
+
+    let mut projections: Vec<ExprRef> = hoisted
+        .values()
+        .map(|(expr, name)| expr.clone().alias(name.as_str()))
+        .collect();
+    projections.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+
+    let rewritten = exprs
+        .iter()
+        .map(|expr| {
+            expr.clone()
+                .transform_down(&|sub: ExprRef| {
+                    match hoisted.get(&structural_hash(&sub)) {
+                        Some((canonical, name)) if sub.as_ref() == canonical.as_ref() => {
+                            Ok(Transformed::yes(col(name.as_str())))
+                        }
+                        _ => Ok(Transformed::no(sub)),
+                    }
+                })
+                .map(|t| t.data)
+                .unwrap_or(expr.clone())
+        })
+        .collect();
+
+    (rewritten, projections)
+}
+
+/// A structural fingerprint of an expression subtree, independent of sharing.
+fn structural_hash(expr: &ExprRef) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    // `Expr`'s derived `Hash` already folds in the whole subtree structure.
+    expr.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Leaf-like expressions cheap enough that hoisting would not pay off.
+fn is_trivial(expr: &ExprRef) -> bool {
+    matches!(
+        expr.as_ref(),
+        Expr::Column(_) | Expr::Literal(_) | Expr::Alias(_, _)
+    )
+}
+
+/// Whether `expr` contains a volatile or side-effecting subexpression. Hoisting
+/// such a subtree would collapse several evaluations into one and change the
+/// result, so these are never deduplicated. Python UDFs are treated as volatile
+/// because the engine cannot prove they are pure.
+fn is_volatile(expr: &ExprRef) -> bool {
+    let mut volatile = false;
+    let _ = expr.apply(&mut |sub: &ExprRef| {
+        if matches!(
+            sub.as_ref(),
+            Expr::Function {
+                func: FunctionExpr::Python(_),
+                ..
+            }
+        ) {
+            volatile = true;
+            return Ok(common_treenode::TreeNodeRecursion::Stop);
+        }
+        Ok(common_treenode::TreeNodeRecursion::Continue)
+    });
+    volatile
+}
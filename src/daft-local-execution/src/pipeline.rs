@@ -1,18 +1,22 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use common_error::DaftResult;
-use common_treenode::{ConcreteTreeNode, TreeNode};
-use daft_core::schema::Schema;
-use daft_dsl::Expr;
+use common_treenode::{ConcreteTreeNode, Transformed, TreeNode};
+use daft_core::{join::JoinType, schema::Schema};
+use daft_dsl::{col, Expr};
 use daft_micropartition::MicroPartition;
 use daft_physical_plan::{
-    Concat, Filter, HashAggregate, HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, PhysicalScan,
-    Project, Sort, UnGroupedAggregate,
+    AsOfJoin, Concat, Filter, HashAggregate, HashJoin, InMemoryScan, Limit, LocalPhysicalPlan,
+    NestedLoopJoin, PhysicalScan, Project, Sort, UnGroupedAggregate,
 };
 use daft_plan::populate_aggregation_stages;
 
 use crate::{
     channel::MultiSender,
+    cse::eliminate_common_subexpressions,
     intermediate_ops::{
         aggregate::AggregateOperator,
         filter::FilterOperator,
@@ -21,11 +25,15 @@ use crate::{
     },
     sinks::{
         aggregate::AggregateSink,
+        asof_join::AsOfJoinSink,
         concat::ConcatSink,
         hash_join::HashJoinSink,
         limit::LimitSink,
+        nested_loop_join::NestedLoopJoinSink,
+        symmetric_hash_join::SymmetricHashJoinSink,
         sink::{run_double_input_sink, run_single_input_sink, DoubleInputSink, SingleInputSink},
         sort::SortSink,
+        top_n::TopNSink,
     },
     sources::{
         in_memory::InMemorySource,
@@ -85,9 +93,22 @@ impl PipelineNode {
     }
 }
 
+/// Lower a physical plan into a runnable pipeline and run the local optimizer
+/// passes over it before it is handed to [`PipelineNode::start`].
 pub fn physical_plan_to_pipeline(
     physical_plan: &LocalPhysicalPlan,
     psets: &HashMap<String, Vec<Arc<MicroPartition>>>,
+) -> DaftResult<PipelineNode> {
+    let node = build_pipeline(physical_plan, psets)?;
+    let rules: Vec<Box<dyn PhysicalOptimizerRule>> = vec![Box::new(ProjectionPushdown::new(
+        required_columns(physical_plan),
+    ))];
+    optimize(node, &rules)
+}
+
+fn build_pipeline(
+    physical_plan: &LocalPhysicalPlan,
+    psets: &HashMap<String, Vec<Arc<MicroPartition>>>,
 ) -> DaftResult<PipelineNode> {
     Ok(match physical_plan {
         LocalPhysicalPlan::PhysicalScan(PhysicalScan { scan_tasks, .. }) => {
@@ -106,10 +127,33 @@ pub fn physical_plan_to_pipeline(
         LocalPhysicalPlan::Project(Project {
             input, projection, ..
         }) => {
-            let proj_op = ProjectOperator::new(projection.clone());
-            let child_node = physical_plan_to_pipeline(input, psets)?;
+            // Common-subexpression elimination: hoist subtrees repeated across the
+            // projection into synthetic columns computed once by an upstream
+            // projection, then reference them from the outer projection.
+            let (projection, hoisted) = eliminate_common_subexpressions(projection);
+            let child_node = build_pipeline(input, psets)?;
+            let child_node = if hoisted.is_empty() {
+                child_node
+            } else {
+                // Pass the input columns the outer projection references through
+                // alongside the hoisted columns so it can still read them directly.
+                let mut referenced = HashSet::new();
+                collect_exprs(&projection, &mut referenced);
+                let mut pre: Vec<daft_dsl::ExprRef> = input
+                    .schema()
+                    .fields
+                    .keys()
+                    .filter(|name| referenced.contains(name.as_str()))
+                    .map(|name| col(name.as_str()))
+                    .collect();
+                pre.extend(hoisted);
+                PipelineNode::IntermediateOp {
+                    intermediate_op: Arc::new(ProjectOperator::new(pre)),
+                    children: vec![child_node],
+                }
+            };
             PipelineNode::IntermediateOp {
-                intermediate_op: Arc::new(proj_op),
+                intermediate_op: Arc::new(ProjectOperator::new(projection)),
                 children: vec![child_node],
             }
         }
@@ -117,17 +161,40 @@ pub fn physical_plan_to_pipeline(
             input, predicate, ..
         }) => {
             let filter_op = FilterOperator::new(predicate.clone());
-            let child_node = physical_plan_to_pipeline(input, psets)?;
+            let child_node = build_pipeline(input, psets)?;
             PipelineNode::IntermediateOp {
                 intermediate_op: Arc::new(filter_op),
                 children: vec![child_node],
             }
         }
+        // Fuse `Limit` over `Sort` into a single bounded Top-N sink, avoiding a
+        // full materialize-and-sort of the input.
+        LocalPhysicalPlan::Limit(Limit {
+            input: sort_input,
+            num_rows,
+            ..
+        }) if matches!(sort_input.as_ref(), LocalPhysicalPlan::Sort(_)) => {
+            let LocalPhysicalPlan::Sort(Sort {
+                input,
+                sort_by,
+                descending,
+                ..
+            }) = sort_input.as_ref()
+            else {
+                unreachable!("guarded by matches! above")
+            };
+            let sink = TopNSink::new(sort_by.clone(), descending.clone(), *num_rows as usize);
+            let child_node = build_pipeline(input, psets)?;
+            PipelineNode::SingleInputSink {
+                sink: Box::new(sink),
+                child: Box::new(child_node),
+            }
+        }
         LocalPhysicalPlan::Limit(Limit {
             input, num_rows, ..
         }) => {
             let sink = LimitSink::new(*num_rows as usize);
-            let child_node = physical_plan_to_pipeline(input, psets)?;
+            let child_node = build_pipeline(input, psets)?;
             PipelineNode::SingleInputSink {
                 sink: Box::new(sink),
                 child: Box::new(child_node),
@@ -135,8 +202,8 @@ pub fn physical_plan_to_pipeline(
         }
         LocalPhysicalPlan::Concat(Concat { input, other, .. }) => {
             let sink = ConcatSink::new();
-            let left_child = physical_plan_to_pipeline(input, psets)?;
-            let right_child = physical_plan_to_pipeline(other, psets)?;
+            let left_child = build_pipeline(input, psets)?;
+            let right_child = build_pipeline(other, psets)?;
             PipelineNode::DoubleInputSink {
                 sink: Box::new(sink),
                 left_child: Box::new(left_child),
@@ -169,7 +236,7 @@ pub fn physical_plan_to_pipeline(
             );
             let final_stage_project = ProjectOperator::new(final_exprs);
 
-            let child_node = physical_plan_to_pipeline(input, psets)?;
+            let child_node = build_pipeline(input, psets)?;
             let intermediate_agg_op_node = PipelineNode::IntermediateOp {
                 intermediate_op: Arc::new(first_stage_agg_op),
                 children: vec![child_node],
@@ -212,7 +279,7 @@ pub fn physical_plan_to_pipeline(
             );
             let final_stage_project = ProjectOperator::new(final_exprs);
 
-            let child_node = physical_plan_to_pipeline(input, psets)?;
+            let child_node = build_pipeline(input, psets)?;
             let intermediate_agg_op_node = PipelineNode::IntermediateOp {
                 intermediate_op: Arc::new(first_stage_agg_op),
                 children: vec![child_node],
@@ -235,7 +302,7 @@ pub fn physical_plan_to_pipeline(
             ..
         }) => {
             let sort_sink = SortSink::new(sort_by.clone(), descending.clone());
-            let child_node = physical_plan_to_pipeline(input, psets)?;
+            let child_node = build_pipeline(input, psets)?;
             PipelineNode::SingleInputSink {
                 sink: Box::new(sort_sink),
                 child: Box::new(child_node),
@@ -251,15 +318,72 @@ pub fn physical_plan_to_pipeline(
         }) => {
             let left_schema = left.schema();
             let right_schema = right.schema();
-            let left_node = physical_plan_to_pipeline(left, psets)?;
-            let right_node = physical_plan_to_pipeline(right, psets)?;
-            let sink = HashJoinSink::new(
+            let left_node = build_pipeline(left, psets)?;
+            let right_node = build_pipeline(right, psets)?;
+            // When both inputs stream, a symmetric hash join produces output
+            // incrementally rather than blocking on one side to build first.
+            // Outer variants still need the blocking build so unmatched rows can
+            // be flushed, so only inner joins take the symmetric path.
+            let symmetric = *join_type == JoinType::Inner && is_streaming(left) && is_streaming(right);
+            let sink: Box<dyn DoubleInputSink> = if symmetric {
+                Box::new(SymmetricHashJoinSink::new(
+                    left_on.clone(),
+                    right_on.clone(),
+                    *join_type,
+                )?)
+            } else {
+                Box::new(HashJoinSink::new(
+                    left_on.clone(),
+                    right_on.clone(),
+                    *join_type,
+                    left_schema,
+                    right_schema,
+                )?)
+            };
+            PipelineNode::DoubleInputSink {
+                sink,
+                left_child: Box::new(left_node),
+                right_child: Box::new(right_node),
+            }
+        }
+        LocalPhysicalPlan::NestedLoopJoin(NestedLoopJoin {
+            left,
+            right,
+            predicate,
+            join_type,
+            ..
+        }) => {
+            let left_node = build_pipeline(left, psets)?;
+            let right_node = build_pipeline(right, psets)?;
+            // An empty predicate degenerates to a cross join over all row pairs.
+            let sink = NestedLoopJoinSink::new(predicate.clone(), *join_type);
+            PipelineNode::DoubleInputSink {
+                sink: Box::new(sink),
+                left_child: Box::new(left_node),
+                right_child: Box::new(right_node),
+            }
+        }
+        LocalPhysicalPlan::AsOfJoin(AsOfJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            by_left,
+            by_right,
+            direction,
+            tolerance,
+            ..
+        }) => {
+            let left_node = build_pipeline(left, psets)?;
+            let right_node = build_pipeline(right, psets)?;
+            let sink = AsOfJoinSink::new(
                 left_on.clone(),
                 right_on.clone(),
-                *join_type,
-                left_schema,
-                right_schema,
-            )?;
+                by_left.clone(),
+                by_right.clone(),
+                *direction,
+                tolerance.clone(),
+            );
             PipelineNode::DoubleInputSink {
                 sink: Box::new(sink),
                 left_child: Box::new(left_node),
@@ -272,28 +396,249 @@ pub fn physical_plan_to_pipeline(
     })
 }
 
-// impl TreeNode for PipelineNode {
-//     fn apply_children<F: FnMut(&Self) -> DaftResult<common_treenode::TreeNodeRecursion>>(
-//             &self,
-//             f: F,
-//         ) -> DaftResult<common_treenode::TreeNodeRecursion> {
-        
-//     }
-    
-//     // fn children(&self) -> Vec<&Self> {
-//     //     use PipelineNode::*;
-//     //     match self.as_ref() {
-//     //         Source { .. } => vec![],
-//     //         IntermediateOp { child, ..} | SingleInputSink { child, ..} => vec![child],
-//     //         DoubleInputSink {left_child, right_child,.. } => vec![left_child, right_child],
-//     //     }
-//     // }
-//     // fn take_children(self) -> (Self, Vec<Self>) {
-//     //     use PipelineNode::*;
-//     //     match self {
-//     //         Source { source } => vec![],
-//     //         IntermediateOp { child, ..} | SingleInputSink { child, ..} => vec![child],
-//     //         DoubleInputSink {left_child, right_child,.. } => vec![left_child, right_child],
-//     //     } 
-//     // }
-// }
\ No newline at end of file
+/// Whether a plan emits its output incrementally (scans and element-wise ops)
+/// rather than buffering all input first (sorts, aggregations, hash-join build).
+fn is_streaming(plan: &LocalPhysicalPlan) -> bool {
+    match plan {
+        LocalPhysicalPlan::PhysicalScan(_) | LocalPhysicalPlan::InMemoryScan(_) => true,
+        LocalPhysicalPlan::Project(Project { input, .. })
+        | LocalPhysicalPlan::Filter(Filter { input, .. })
+        | LocalPhysicalPlan::Limit(Limit { input, .. }) => is_streaming(input),
+        _ => false,
+    }
+}
+
+impl ConcreteTreeNode for PipelineNode {
+    fn children(&self) -> Vec<&Self> {
+        use PipelineNode::*;
+        match self {
+            Source { .. } => vec![],
+            IntermediateOp { children, .. } => children.iter().collect(),
+            SingleInputSink { child, .. } => vec![child],
+            DoubleInputSink {
+                left_child,
+                right_child,
+                ..
+            } => vec![left_child, right_child],
+        }
+    }
+
+    fn take_children(self) -> (Self, Vec<Self>) {
+        use PipelineNode::*;
+        match self {
+            Source { source } => (Source { source }, vec![]),
+            IntermediateOp {
+                intermediate_op,
+                children,
+            } => (
+                IntermediateOp {
+                    intermediate_op,
+                    children: vec![],
+                },
+                children,
+            ),
+            SingleInputSink { sink, child } => (
+                // Re-seat with a placeholder that `with_new_children` replaces.
+                SingleInputSink {
+                    sink,
+                    child: Box::new(placeholder()),
+                },
+                vec![*child],
+            ),
+            DoubleInputSink {
+                sink,
+                left_child,
+                right_child,
+            } => (
+                DoubleInputSink {
+                    sink,
+                    left_child: Box::new(placeholder()),
+                    right_child: Box::new(placeholder()),
+                },
+                vec![*left_child, *right_child],
+            ),
+        }
+    }
+
+    fn with_new_children(self, mut children: Vec<Self>) -> DaftResult<Self> {
+        use PipelineNode::*;
+        Ok(match self {
+            Source { source } => {
+                assert!(children.is_empty(), "Source takes no children");
+                Source { source }
+            }
+            IntermediateOp {
+                intermediate_op, ..
+            } => IntermediateOp {
+                intermediate_op,
+                children,
+            },
+            SingleInputSink { sink, .. } => {
+                assert_eq!(children.len(), 1, "SingleInputSink takes one child");
+                SingleInputSink {
+                    sink,
+                    child: Box::new(children.pop().unwrap()),
+                }
+            }
+            DoubleInputSink { sink, .. } => {
+                assert_eq!(children.len(), 2, "DoubleInputSink takes two children");
+                let right_child = Box::new(children.pop().unwrap());
+                let left_child = Box::new(children.pop().unwrap());
+                DoubleInputSink {
+                    sink,
+                    left_child,
+                    right_child,
+                }
+            }
+        })
+    }
+}
+
+/// A rewrite rule over the pipeline tree, run before [`PipelineNode::start`].
+pub trait PhysicalOptimizerRule {
+    fn rewrite(&self, node: PipelineNode) -> DaftResult<Transformed<PipelineNode>>;
+}
+
+/// Run each optimization pass once over the pipeline tree, in order.
+pub fn optimize(
+    node: PipelineNode,
+    rules: &[Box<dyn PhysicalOptimizerRule>],
+) -> DaftResult<PipelineNode> {
+    let mut node = node;
+    for rule in rules {
+        node = rule.rewrite(node)?.data;
+    }
+    Ok(node)
+}
+
+/// Projection pushdown: drop columns that no operator downstream references,
+/// inserting a narrowing projection just above each source. The set of required
+/// columns is the output columns of the plan root plus every column named by any
+/// projection / predicate / join key / group-by / aggregate in the plan; a source
+/// only needs to emit the intersection of that set with its own schema. Pruning
+/// at the source is the first of the optimize-projections passes (filter pushdown
+/// and operator fusion slot in as further rules over the same tree).
+pub struct ProjectionPushdown {
+    required: HashSet<String>,
+}
+
+impl ProjectionPushdown {
+    pub fn new(required: HashSet<String>) -> Self {
+        Self { required }
+    }
+}
+
+impl PhysicalOptimizerRule for ProjectionPushdown {
+    fn rewrite(&self, node: PipelineNode) -> DaftResult<Transformed<PipelineNode>> {
+        node.transform_up(&|n| match n {
+            PipelineNode::Source { source } => {
+                // Keep only the source columns something downstream reads, in the
+                // source's own column order.
+                let schema = source.schema();
+                let keep: Vec<String> = schema
+                    .fields
+                    .keys()
+                    .filter(|name| self.required.contains(name.as_str()))
+                    .cloned()
+                    .collect();
+                if keep.len() == schema.fields.len() {
+                    // Nothing to prune; leave the source untouched.
+                    return Ok(Transformed::no(PipelineNode::Source { source }));
+                }
+                let projection = keep.iter().map(|name| col(name.as_str())).collect();
+                Ok(Transformed::yes(PipelineNode::IntermediateOp {
+                    intermediate_op: Arc::new(ProjectOperator::new(projection)),
+                    children: vec![PipelineNode::Source { source }],
+                }))
+            }
+            other => Ok(Transformed::no(other)),
+        })
+    }
+}
+
+/// Collect every column name referenced by the expressions anywhere in a physical
+/// plan, seeded with the plan's output columns (always required). This is the
+/// column-requirement walk [`ProjectionPushdown`] prunes against.
+fn required_columns(plan: &LocalPhysicalPlan) -> HashSet<String> {
+    let mut required: HashSet<String> = plan.schema().fields.keys().cloned().collect();
+    collect_required(plan, &mut required);
+    required
+}
+
+fn collect_required(plan: &LocalPhysicalPlan, out: &mut HashSet<String>) {
+    use LocalPhysicalPlan::*;
+    match plan {
+        PhysicalScan(_) | InMemoryScan(_) => {}
+        Project(p) => {
+            collect_exprs(&p.projection, out);
+            collect_required(&p.input, out);
+        }
+        Filter(f) => {
+            collect_expr(&f.predicate, out);
+            collect_required(&f.input, out);
+        }
+        Limit(l) => collect_required(&l.input, out),
+        Sort(s) => {
+            collect_exprs(&s.sort_by, out);
+            collect_required(&s.input, out);
+        }
+        Concat(c) => {
+            collect_required(&c.input, out);
+            collect_required(&c.other, out);
+        }
+        UnGroupedAggregate(a) => {
+            collect_exprs(&a.aggregations, out);
+            collect_required(&a.input, out);
+        }
+        HashAggregate(a) => {
+            collect_exprs(&a.aggregations, out);
+            collect_exprs(&a.group_by, out);
+            collect_required(&a.input, out);
+        }
+        HashJoin(j) => {
+            collect_exprs(&j.left_on, out);
+            collect_exprs(&j.right_on, out);
+            collect_required(&j.left, out);
+            collect_required(&j.right, out);
+        }
+        NestedLoopJoin(j) => {
+            if let Some(predicate) = &j.predicate {
+                collect_expr(predicate, out);
+            }
+            collect_required(&j.left, out);
+            collect_required(&j.right, out);
+        }
+        AsOfJoin(j) => {
+            collect_exprs(&j.left_on, out);
+            collect_exprs(&j.right_on, out);
+            collect_exprs(&j.by_left, out);
+            collect_exprs(&j.by_right, out);
+            collect_required(&j.left, out);
+            collect_required(&j.right, out);
+        }
+    }
+}
+
+fn collect_exprs(exprs: &[daft_dsl::ExprRef], out: &mut HashSet<String>) {
+    for expr in exprs {
+        collect_expr(expr, out);
+    }
+}
+
+fn collect_expr(expr: &daft_dsl::ExprRef, out: &mut HashSet<String>) {
+    let _ = expr.apply(&mut |e: &daft_dsl::ExprRef| {
+        if let Expr::Column(name) = e.as_ref() {
+            out.insert(name.to_string());
+        }
+        Ok(common_treenode::TreeNodeRecursion::Continue)
+    });
+}
+
+/// A detached node used only as a transient placeholder while children are moved
+/// out of a variant in [`ConcreteTreeNode::take_children`].
+fn placeholder() -> PipelineNode {
+    PipelineNode::IntermediateOp {
+        intermediate_op: Arc::new(crate::intermediate_ops::project::ProjectOperator::new(vec![])),
+        children: vec![],
+    }
+}
\ No newline at end of file
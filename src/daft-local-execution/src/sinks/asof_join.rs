@@ -0,0 +1,259 @@
+use std::{collections::HashMap, sync::Arc};
+
+use arrow2::array::PrimitiveArray;
+use common_error::{DaftError, DaftResult};
+use daft_core::datatypes::{DataType, Field};
+use daft_core::series::Series;
+use daft_dsl::{Expr, ExprRef, LiteralValue};
+use daft_micropartition::MicroPartition;
+
+use super::sink::{DoubleInputSink, SinkResultType};
+
+/// Search direction for the nearest-key match.
+#[derive(Debug, Clone, Copy)]
+pub enum AsOfDirection {
+    /// Match the greatest right key `<= left_key`.
+    Backward,
+    /// Match the least right key `>= left_key`.
+    Forward,
+}
+
+/// As-of (nearest-key) join sink. Both sides are buffered in full, then
+/// `finalize` buckets the right side by its `by` key and walks each left row
+/// against the matching bucket to find the nearest right row in `direction`,
+/// within the optional `tolerance`. Left rows with no match (empty bucket, or
+/// nothing within tolerance) null-pad the right-hand columns; left order is
+/// preserved throughout.
+pub struct AsOfJoinSink {
+    left_on: ExprRef,
+    right_on: ExprRef,
+    by_left: Vec<ExprRef>,
+    by_right: Vec<ExprRef>,
+    direction: AsOfDirection,
+    tolerance: Option<ExprRef>,
+    left: Vec<Arc<MicroPartition>>,
+    right: Vec<Arc<MicroPartition>>,
+}
+
+impl AsOfJoinSink {
+    pub fn new(
+        left_on: ExprRef,
+        right_on: ExprRef,
+        by_left: Vec<ExprRef>,
+        by_right: Vec<ExprRef>,
+        direction: AsOfDirection,
+        tolerance: Option<ExprRef>,
+    ) -> Self {
+        Self {
+            left_on,
+            right_on,
+            by_left,
+            by_right,
+            direction,
+            tolerance,
+            left: vec![],
+            right: vec![],
+        }
+    }
+}
+
+impl DoubleInputSink for AsOfJoinSink {
+    fn sink_left(&mut self, input: &Arc<MicroPartition>) -> DaftResult<SinkResultType> {
+        self.left.push(input.clone());
+        Ok(SinkResultType::NeedMoreInput)
+    }
+
+    fn sink_right(&mut self, input: &Arc<MicroPartition>) -> DaftResult<SinkResultType> {
+        self.right.push(input.clone());
+        Ok(SinkResultType::NeedMoreInput)
+    }
+
+    fn in_order(&self) -> bool {
+        true
+    }
+
+    fn finalize(&mut self) -> DaftResult<Vec<Arc<MicroPartition>>> {
+        let left = MicroPartition::concat(&std::mem::take(&mut self.left))?;
+        let right = MicroPartition::concat(&std::mem::take(&mut self.right))?;
+        let joined = asof_join(
+            &left,
+            &right,
+            &self.left_on,
+            &self.right_on,
+            &self.by_left,
+            &self.by_right,
+            self.direction,
+            self.tolerance.as_ref(),
+        )?;
+        Ok(vec![Arc::new(joined)])
+    }
+
+    fn name(&self) -> &'static str {
+        "AsOfJoin"
+    }
+}
+
+/// The merge-walk itself: bucket `right` by its `by` key, sort each bucket by
+/// `right_on`, then for every `left` row binary-search its bucket for the
+/// nearest key in `direction` (subject to `tolerance`). Unmatched left rows
+/// get a null-padded right side via a nullable gather index, so the result
+/// has exactly `left.len()` rows in `left`'s original order.
+fn asof_join(
+    left: &MicroPartition,
+    right: &MicroPartition,
+    left_on: &ExprRef,
+    right_on: &ExprRef,
+    by_left: &[ExprRef],
+    by_right: &[ExprRef],
+    direction: AsOfDirection,
+    tolerance: Option<&ExprRef>,
+) -> DaftResult<MicroPartition> {
+    let left_keys = eval_f64_column(left, left_on)?;
+    let right_keys = eval_f64_column(right, right_on)?;
+    let left_groups = eval_group_keys(left, by_left)?;
+    let right_groups = eval_group_keys(right, by_right)?;
+    let tolerance = tolerance.map(literal_f64).transpose()?;
+
+    let mut buckets: HashMap<Vec<Option<u64>>, Vec<(f64, u64)>> = HashMap::new();
+    for (idx, (key, group)) in right_keys.iter().zip(right_groups.iter()).enumerate() {
+        if let Some(key) = key {
+            buckets
+                .entry(group.clone())
+                .or_default()
+                .push((*key, idx as u64));
+        }
+    }
+    for bucket in buckets.values_mut() {
+        bucket.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+    }
+
+    let right_idx: Vec<Option<u64>> = left_keys
+        .iter()
+        .zip(left_groups.iter())
+        .map(|(key, group)| {
+            let key = (*key)?;
+            let bucket = buckets.get(group)?;
+            nearest(bucket, key, direction, tolerance)
+        })
+        .collect();
+
+    let right_idx = Series::from_arrow(
+        Field::new("", DataType::UInt64).into(),
+        Box::new(PrimitiveArray::<u64>::from_trusted_len_iter(
+            right_idx.into_iter(),
+        )),
+    )?;
+    left.hstack(&right.take(&right_idx)?)
+}
+
+/// Find the nearest `(key, row_idx)` to `target` in `bucket` (sorted
+/// ascending by key) for `direction`, or `None` if nothing qualifies within
+/// `tolerance`.
+fn nearest(
+    bucket: &[(f64, u64)],
+    target: f64,
+    direction: AsOfDirection,
+    tolerance: Option<f64>,
+) -> Option<u64> {
+    let found = match direction {
+        AsOfDirection::Backward => {
+            let split = bucket.partition_point(|&(k, _)| k <= target);
+            bucket[..split].last()
+        }
+        AsOfDirection::Forward => {
+            let split = bucket.partition_point(|&(k, _)| k < target);
+            bucket[split..].first()
+        }
+    }?;
+    match tolerance {
+        Some(tol) if (found.0 - target).abs() > tol => None,
+        _ => Some(found.1),
+    }
+}
+
+/// Evaluate `expr` against `partition` and coerce the result to `Vec<Option<f64>>`,
+/// matching the numeric-only bound checks the statistics-pruning `filter` path
+/// already relies on.
+fn eval_f64_column(partition: &MicroPartition, expr: &ExprRef) -> DaftResult<Vec<Option<f64>>> {
+    let tables = partition.concat_or_get()?;
+    let single = match tables.as_slice() {
+        [] => return Ok(vec![]),
+        [single] => single,
+        _ => unreachable!(),
+    };
+    let series = single.eval_expression(expr)?.cast(&DataType::Float64)?;
+    Ok(series.f64()?.into_iter().map(|v| v.copied()).collect())
+}
+
+/// Evaluate each `by` expression into one `Option<u64>` bit-pattern column per
+/// row (a `Vec::new()` group key when there are no `by` columns), so rows can
+/// be bucketed by exact equality without relying on `f64: Eq`.
+fn eval_group_keys(partition: &MicroPartition, by: &[ExprRef]) -> DaftResult<Vec<Vec<Option<u64>>>> {
+    let columns = by
+        .iter()
+        .map(|expr| eval_f64_column(partition, expr))
+        .collect::<DaftResult<Vec<_>>>()?;
+    let len = columns.first().map_or(partition.len(), |c| c.len());
+    Ok((0..len)
+        .map(|row| {
+            columns
+                .iter()
+                .map(|col| col[row].map(f64::to_bits))
+                .collect()
+        })
+        .collect())
+}
+
+/// Extract a constant `f64` threshold out of a literal `tolerance` expression.
+fn literal_f64(expr: &ExprRef) -> DaftResult<f64> {
+    let Expr::Literal(lit) = expr.as_ref() else {
+        return Err(DaftError::ValueError(
+            "asof_join tolerance must be a literal".to_string(),
+        ));
+    };
+    match lit {
+        LiteralValue::Int32(v) => Ok(*v as f64),
+        LiteralValue::UInt32(v) => Ok(*v as f64),
+        LiteralValue::Int64(v) => Ok(*v as f64),
+        LiteralValue::UInt64(v) => Ok(*v as f64),
+        LiteralValue::Float64(v) => Ok(*v),
+        other => Err(DaftError::ValueError(format!(
+            "asof_join tolerance must be numeric, got {other:?}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket() -> Vec<(f64, u64)> {
+        vec![(1.0, 0), (3.0, 1), (3.0, 2), (7.0, 3)]
+    }
+
+    #[test]
+    fn backward_matches_greatest_key_not_exceeding_target() {
+        assert_eq!(nearest(&bucket(), 5.0, AsOfDirection::Backward, None), Some(2));
+        assert_eq!(nearest(&bucket(), 3.0, AsOfDirection::Backward, None), Some(2));
+        assert_eq!(nearest(&bucket(), 0.5, AsOfDirection::Backward, None), None);
+    }
+
+    #[test]
+    fn forward_matches_least_key_not_below_target() {
+        assert_eq!(nearest(&bucket(), 5.0, AsOfDirection::Forward, None), Some(3));
+        assert_eq!(nearest(&bucket(), 3.0, AsOfDirection::Forward, None), Some(1));
+        assert_eq!(nearest(&bucket(), 7.5, AsOfDirection::Forward, None), None);
+    }
+
+    #[test]
+    fn tolerance_rejects_matches_too_far_from_target() {
+        assert_eq!(
+            nearest(&bucket(), 5.0, AsOfDirection::Backward, Some(1.0)),
+            None
+        );
+        assert_eq!(
+            nearest(&bucket(), 5.0, AsOfDirection::Backward, Some(2.0)),
+            Some(2)
+        );
+    }
+}
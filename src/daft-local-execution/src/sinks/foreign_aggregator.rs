@@ -0,0 +1,370 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    sync::Arc,
+};
+
+use arrow2::array::PrimitiveArray;
+use common_error::DaftResult;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+
+use super::sink::{SingleInputSink, SinkResultType};
+
+/// A pluggable ("foreign") aggregator: callers register custom aggregations
+/// that [`ForeignAggregateSink`] drives via `init`/`accumulate`/`finalize`.
+/// `merge` combines two partial states -- [`ForeignAggregateSink`] builds one
+/// fresh partial per morsel (per group) via `init`+`accumulate` and folds it
+/// into the running per-group state via `merge`, the same partial/merge split
+/// the built-in `Expr::Agg` aggregates use. State is a boxed trait object so
+/// each aggregator chooses its own representation.
+pub trait ForeignAggregator: Send + Sync {
+    /// A fresh, empty accumulator state.
+    fn init(&self) -> Box<dyn AggregatorState>;
+    /// Fold a chunk of input values into `state`.
+    fn accumulate(&self, state: &mut dyn AggregatorState, input: &Series) -> DaftResult<()>;
+    /// Combine two partial states produced on different partitions.
+    fn merge(
+        &self,
+        left: Box<dyn AggregatorState>,
+        right: Box<dyn AggregatorState>,
+    ) -> DaftResult<Box<dyn AggregatorState>>;
+    /// Produce the finalized single-row result `Series`.
+    fn finalize(&self, state: Box<dyn AggregatorState>) -> DaftResult<Series>;
+    fn name(&self) -> &'static str;
+}
+
+/// Opaque per-aggregator accumulator state. Downcast in the owning aggregator.
+pub trait AggregatorState: std::any::Any + Send + Sync {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any>;
+}
+
+impl<T: std::any::Any + Send + Sync> AggregatorState for T {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+/// Group-concat: joins string values with a separator.
+pub struct StringAgg {
+    pub separator: String,
+}
+
+impl ForeignAggregator for StringAgg {
+    fn init(&self) -> Box<dyn AggregatorState> {
+        Box::new(Vec::<String>::new())
+    }
+
+    fn accumulate(&self, state: &mut dyn AggregatorState, input: &Series) -> DaftResult<()> {
+        let acc = state.as_any_mut().downcast_mut::<Vec<String>>().unwrap();
+        let utf8 = input.utf8()?;
+        for value in utf8.into_iter().flatten() {
+            acc.push(value.to_string());
+        }
+        Ok(())
+    }
+
+    fn merge(
+        &self,
+        left: Box<dyn AggregatorState>,
+        right: Box<dyn AggregatorState>,
+    ) -> DaftResult<Box<dyn AggregatorState>> {
+        let mut left = left.into_any().downcast::<Vec<String>>().unwrap();
+        let right = right.into_any().downcast::<Vec<String>>().unwrap();
+        left.extend(*right);
+        Ok(left)
+    }
+
+    fn finalize(&self, state: Box<dyn AggregatorState>) -> DaftResult<Series> {
+        let acc = state.into_any().downcast::<Vec<String>>().unwrap();
+        Series::from_str_slice("string_agg", &[acc.join(&self.separator)])
+    }
+
+    fn name(&self) -> &'static str {
+        "string_agg"
+    }
+}
+
+/// Per-accumulation state for [`TopK`]: the bounded heap plus the original
+/// input type, so `finalize` can cast the `f64` working values back to
+/// whatever numeric type was actually aggregated instead of always emitting
+/// `Float64`.
+#[derive(Default)]
+struct TopKState {
+    heap: BinaryHeap<OrderedValue>,
+    dtype: Option<DataType>,
+}
+
+/// Returns the `k` largest values per group using a bounded min-heap.
+pub struct TopK {
+    pub k: usize,
+}
+
+impl ForeignAggregator for TopK {
+    fn init(&self) -> Box<dyn AggregatorState> {
+        Box::<TopKState>::default()
+    }
+
+    fn accumulate(&self, state: &mut dyn AggregatorState, input: &Series) -> DaftResult<()> {
+        let state = state.as_any_mut().downcast_mut::<TopKState>().unwrap();
+        if state.dtype.is_none() {
+            state.dtype = Some(input.data_type().clone());
+        }
+        // Compare on `f64` so the heap works for any numeric input (int/uint/
+        // float), matching how the built-in numeric aggregates widen their input.
+        let values = input.cast(&DataType::Float64)?;
+        for value in values.f64()?.into_iter().flatten() {
+            // Min-heap (Reverse): the smallest kept value is at the root, so once
+            // `k` are retained each larger value evicts the current minimum.
+            state.heap.push(OrderedValue(std::cmp::Reverse(value)));
+            if state.heap.len() > self.k {
+                state.heap.pop();
+            }
+        }
+        Ok(())
+    }
+
+    fn merge(
+        &self,
+        left: Box<dyn AggregatorState>,
+        right: Box<dyn AggregatorState>,
+    ) -> DaftResult<Box<dyn AggregatorState>> {
+        let mut left = left.into_any().downcast::<TopKState>().unwrap();
+        let right = right.into_any().downcast::<TopKState>().unwrap();
+        left.dtype = left.dtype.take().or(right.dtype);
+        for value in right.heap {
+            left.heap.push(value);
+            if left.heap.len() > self.k {
+                left.heap.pop();
+            }
+        }
+        Ok(left)
+    }
+
+    fn finalize(&self, state: Box<dyn AggregatorState>) -> DaftResult<Series> {
+        let state = state.into_any().downcast::<TopKState>().unwrap();
+        let mut values: Vec<f64> = state.heap.into_iter().map(|v| v.0 .0).collect();
+        values.sort_unstable_by(|a, b| b.partial_cmp(a).unwrap());
+        let result = Series::from_f64_slice("top_k", &values)?;
+        match state.dtype {
+            Some(dtype) => result.cast(&dtype),
+            None => Ok(result),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "top_k"
+    }
+}
+
+/// Resolve an aggregate name to its foreign aggregator, so a physical-plan
+/// lowering that recognizes a name the built-in planner doesn't can construct
+/// a [`ForeignAggregateSink`] around it. Returning `None` lets the caller fall
+/// back to the built-in `Expr::Agg` path.
+pub fn foreign_aggregator(name: &str, args: &ForeignAggArgs) -> Option<Box<dyn ForeignAggregator>> {
+    match name {
+        "string_agg" => Some(Box::new(StringAgg {
+            separator: args.separator.clone().unwrap_or_default(),
+        })),
+        "top_k" => args.k.map(|k| Box::new(TopK { k }) as Box<dyn ForeignAggregator>),
+        _ => None,
+    }
+}
+
+/// One group's accumulated state, plus (when grouped) the `group_by` values
+/// that identify it, captured from the first row seen for this key so
+/// `finalize` can re-attach them without re-deriving the key from its hash.
+struct GroupState {
+    key_values: Vec<Series>,
+    state: Box<dyn AggregatorState>,
+}
+
+/// Drives a [`ForeignAggregator`] over a stream of morsels, grouped by
+/// `group_by` (empty for a single ungrouped result, matching how
+/// `AggregateOperator`/`AggregateSink` take `vec![]` for the ungrouped case).
+/// Each morsel is split into its per-group rows; every group gets a fresh
+/// partial state via `init`+`accumulate` over just that morsel's rows for
+/// that group, which is then folded into the group's running state via
+/// `merge` -- the same partial/merge split the built-in two-stage
+/// `AggregateOperator`/`AggregateSink` pair performs, just within one sink
+/// rather than across two physical-plan nodes. `finalize` reads each group's
+/// state back out via the aggregator's own `finalize`.
+///
+/// Wiring this into `LocalPhysicalPlan`'s dispatch so `populate_aggregation_stages`
+/// can route a foreign aggregate name here instead of the built-in path isn't
+/// done here: that dispatch pattern-matches on the aggregate-expression type
+/// built and owned by `daft-plan`, which isn't part of this tree.
+pub struct ForeignAggregateSink {
+    aggregator: Box<dyn ForeignAggregator>,
+    input: ExprRef,
+    group_by: Vec<ExprRef>,
+    groups: HashMap<u64, GroupState>,
+}
+
+impl ForeignAggregateSink {
+    pub fn new(aggregator: Box<dyn ForeignAggregator>, input: ExprRef, group_by: Vec<ExprRef>) -> Self {
+        Self {
+            aggregator,
+            input,
+            group_by,
+            groups: HashMap::new(),
+        }
+    }
+
+    /// A composite hash key per row of `table`, folding each `group_by`
+    /// column's own per-row hash together. Ungrouped aggregation is just
+    /// grouping where every row hashes to the same (arbitrary) key.
+    fn row_keys(&self, table: &Table) -> DaftResult<Vec<u64>> {
+        let len = table.len();
+        if self.group_by.is_empty() {
+            return Ok(vec![0u64; len]);
+        }
+        let mut keys = vec![0u64; len];
+        for expr in &self.group_by {
+            let hashes = table.eval_expression(expr)?.hash(None)?;
+            for (key, hash) in keys.iter_mut().zip(hashes.u64()?.into_iter()) {
+                *key = key.wrapping_mul(31).wrapping_add(hash.copied().unwrap_or(0));
+            }
+        }
+        Ok(keys)
+    }
+}
+
+fn take_indices(series: &Series, indices: &[u64]) -> DaftResult<Series> {
+    let idx = Series::from_arrow(
+        Field::new("", DataType::UInt64).into(),
+        Box::new(PrimitiveArray::<u64>::from_trusted_len_values_iter(
+            indices.iter().copied(),
+        )),
+    )?;
+    series.take(&idx)
+}
+
+impl SingleInputSink for ForeignAggregateSink {
+    fn sink(&mut self, input: &Arc<MicroPartition>) -> DaftResult<SinkResultType> {
+        for table in input.concat_or_get()?.iter() {
+            let values = table.eval_expression(&self.input)?;
+            let key_columns = self
+                .group_by
+                .iter()
+                .map(|expr| table.eval_expression(expr))
+                .collect::<DaftResult<Vec<_>>>()?;
+
+            let mut row_indices: HashMap<u64, Vec<u64>> = HashMap::new();
+            for (row, key) in self.row_keys(table)?.into_iter().enumerate() {
+                row_indices.entry(key).or_default().push(row as u64);
+            }
+
+            for (key, indices) in row_indices {
+                let partial_values = take_indices(&values, &indices)?;
+                let mut partial = self.aggregator.init();
+                self.aggregator.accumulate(partial.as_mut(), &partial_values)?;
+
+                if !self.groups.contains_key(&key) {
+                    let key_values = key_columns
+                        .iter()
+                        .map(|col| take_indices(col, &indices[..1]))
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    self.groups.insert(
+                        key,
+                        GroupState {
+                            key_values,
+                            state: self.aggregator.init(),
+                        },
+                    );
+                }
+                let existing = std::mem::replace(
+                    &mut self.groups.get_mut(&key).unwrap().state,
+                    self.aggregator.init(),
+                );
+                let merged = self.aggregator.merge(existing, partial)?;
+                self.groups.get_mut(&key).unwrap().state = merged;
+            }
+        }
+        Ok(SinkResultType::NeedMoreInput)
+    }
+
+    fn in_order(&self) -> bool {
+        false
+    }
+
+    fn finalize(&mut self) -> DaftResult<Vec<Arc<MicroPartition>>> {
+        let mut rows = Vec::with_capacity(self.groups.len().max(1));
+        for (_, group) in std::mem::take(&mut self.groups) {
+            let result = self.aggregator.finalize(group.state)?;
+            let columns: Vec<Series> = group
+                .key_values
+                .into_iter()
+                .chain(std::iter::once(result))
+                .collect();
+            let schema = Arc::new(Schema::new(
+                columns
+                    .iter()
+                    .map(|c| Field::new(c.name(), c.data_type().clone()))
+                    .collect(),
+            )?);
+            let table = Table::from_columns(columns)?;
+            rows.push(Arc::new(MicroPartition::from_table(schema, table)));
+        }
+        if rows.is_empty() {
+            // No input rows means no groups at all; still emit the (empty)
+            // aggregator's result for the ungrouped case so downstream sees a
+            // row, matching the previous always-one-row-out behavior.
+            if self.group_by.is_empty() {
+                let result = self.aggregator.finalize(self.aggregator.init())?;
+                let schema = Arc::new(Schema::new(vec![Field::new(
+                    result.name(),
+                    result.data_type().clone(),
+                )])?);
+                let table = Table::from_columns(vec![result])?;
+                rows.push(Arc::new(MicroPartition::from_table(schema, table)));
+            }
+            return Ok(rows);
+        }
+        Ok(vec![Arc::new(MicroPartition::concat(&rows)?)])
+    }
+
+    fn name(&self) -> &'static str {
+        self.aggregator.name()
+    }
+}
+
+/// Parameters parsed from a foreign-aggregate call site.
+#[derive(Debug, Default, Clone)]
+pub struct ForeignAggArgs {
+    pub separator: Option<String>,
+    pub k: Option<usize>,
+}
+
+/// Total-ordering wrapper so `f64`s can live in a `BinaryHeap`.
+struct OrderedValue(std::cmp::Reverse<f64>);
+
+impl PartialEq for OrderedValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 .0 == other.0 .0
+    }
+}
+impl Eq for OrderedValue {}
+impl PartialOrd for OrderedValue {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for OrderedValue {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+             .0
+            .partial_cmp(&other.0 .0)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .reverse()
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::join::JoinType;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+
+use super::sink::{DoubleInputSink, SinkResultType};
+
+/// Cartesian/nested-loop join sink for predicates the hash path can't handle
+/// (non-equi and cross joins). The left side is buffered in full, then the right
+/// side is joined against it by evaluating `predicate` over every row pair. A
+/// `None` predicate is the cross-join fast path that emits all pairs.
+///
+/// Only an inner (or cross) join can emit per right morsel, because each right
+/// row's matches are independent of the other right rows. Left/right/outer joins
+/// null-pad the rows that never match *anywhere*, which is only known once the
+/// whole opposite side has been seen — so those variants buffer both sides and
+/// run a single join at finalize rather than once per morsel (which would
+/// duplicate unmatched rows and compute "unmatched" per morsel).
+///
+/// The streaming inner/cross path emits one output block per right morsel, in
+/// the order right morsels arrive; it does not guarantee the overall output is
+/// ordered by left row, since a left row's matches against different right
+/// morsels land in different blocks.
+pub struct NestedLoopJoinSink {
+    predicate: Option<ExprRef>,
+    join_type: JoinType,
+    left: Vec<Arc<MicroPartition>>,
+    right: Vec<Arc<MicroPartition>>,
+    /// The concatenated left side, materialized once and reused across morsels.
+    left_concat: Option<Arc<MicroPartition>>,
+    result: Vec<Arc<MicroPartition>>,
+}
+
+impl NestedLoopJoinSink {
+    pub fn new(predicate: Option<ExprRef>, join_type: JoinType) -> Self {
+        Self {
+            predicate,
+            join_type,
+            left: vec![],
+            right: vec![],
+            left_concat: None,
+            result: vec![],
+        }
+    }
+
+    /// Whether a right morsel can be emitted immediately rather than buffered for
+    /// a single global join at finalize.
+    fn can_stream(&self) -> bool {
+        self.join_type == JoinType::Inner
+    }
+
+    /// Concatenate the buffered left side once, caching it for reuse.
+    fn left_side(&mut self) -> DaftResult<Arc<MicroPartition>> {
+        if self.left_concat.is_none() {
+            self.left_concat = Some(Arc::new(MicroPartition::concat(&self.left)?));
+        }
+        Ok(self.left_concat.as_ref().unwrap().clone())
+    }
+}
+
+impl DoubleInputSink for NestedLoopJoinSink {
+    fn sink_left(&mut self, input: &Arc<MicroPartition>) -> DaftResult<SinkResultType> {
+        self.left.push(input.clone());
+        Ok(SinkResultType::NeedMoreInput)
+    }
+
+    fn sink_right(&mut self, input: &Arc<MicroPartition>) -> DaftResult<SinkResultType> {
+        if self.can_stream() {
+            // Inner/cross: each right morsel's matches are self-contained, so it
+            // can be joined against the buffered left side and emitted as its own
+            // block immediately. Blocks arrive in right-morsel order, not sorted
+            // by left row, so a given left row's matches can land in more than
+            // one block -- this trades global row order for not having to buffer
+            // the right side.
+            let left = self.left_side()?;
+            self.result.push(Arc::new(left.cross_join(
+                input,
+                self.predicate.as_ref(),
+                self.join_type,
+            )?));
+        } else {
+            // Outer variants need the whole right side before unmatched rows on
+            // either side can be resolved, so defer to `finalize`.
+            self.right.push(input.clone());
+        }
+        Ok(SinkResultType::NeedMoreInput)
+    }
+
+    fn in_order(&self) -> bool {
+        true
+    }
+
+    fn finalize(&mut self) -> DaftResult<Vec<Arc<MicroPartition>>> {
+        if !self.can_stream() {
+            let left = self.left_side()?;
+            let right = MicroPartition::concat(&self.right)?;
+            self.result.push(Arc::new(left.cross_join(
+                &right,
+                self.predicate.as_ref(),
+                self.join_type,
+            )?));
+        }
+        Ok(std::mem::take(&mut self.result))
+    }
+
+    fn name(&self) -> &'static str {
+        "NestedLoopJoin"
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::join::JoinType;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+
+use super::sink::{DoubleInputSink, SinkResultType};
+
+/// Per-side state: the join keys and the table accumulated from that side so far.
+struct SideState {
+    on: Vec<ExprRef>,
+    buffered: Vec<Arc<MicroPartition>>,
+}
+
+impl SideState {
+    fn new(on: Vec<ExprRef>) -> Self {
+        Self {
+            on,
+            buffered: vec![],
+        }
+    }
+}
+
+/// Maintains a hash table per side: a morsel arriving on either input is
+/// inserted into its own table and immediately probed against the opposite
+/// table to emit matches. A match is emitted as soon as both of its rows have
+/// arrived, so an inner join produces output incrementally with no completion
+/// barrier.
+///
+/// Only inner joins are handled: an outer variant cannot emit a row's null-padded
+/// form until it knows the row matched *nowhere*, which defeats the incremental
+/// model without per-row match bitmaps and an anti-join flush. Until that lands,
+/// `physical_plan_to_pipeline` routes outer joins to the blocking `HashJoinSink`,
+/// and `new` rejects them so the sink can never silently downgrade to inner.
+pub struct SymmetricHashJoinSink {
+    left: SideState,
+    right: SideState,
+    result: Vec<Arc<MicroPartition>>,
+}
+
+impl SymmetricHashJoinSink {
+    pub fn new(
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        join_type: JoinType,
+    ) -> DaftResult<Self> {
+        if join_type != JoinType::Inner {
+            return Err(DaftError::ValueError(format!(
+                "SymmetricHashJoinSink only supports inner joins, got {join_type:?}"
+            )));
+        }
+        Ok(Self {
+            left: SideState::new(left_on),
+            right: SideState::new(right_on),
+            result: vec![],
+        })
+    }
+
+    fn probe(
+        build: &mut SideState,
+        probe: &mut SideState,
+        input: &Arc<MicroPartition>,
+        probe_is_left: bool,
+    ) -> DaftResult<Arc<MicroPartition>> {
+        // Insert the incoming morsel into its own side, then probe the opposite
+        // table for matches against the rows accumulated so far.
+        probe.buffered.push(input.clone());
+        let other = MicroPartition::concat(&build.buffered)?;
+        let matches = if probe_is_left {
+            input.hash_join(&other, &probe.on, &build.on, JoinType::Inner)?
+        } else {
+            other.hash_join(input, &build.on, &probe.on, JoinType::Inner)?
+        };
+        Ok(Arc::new(matches))
+    }
+}
+
+impl DoubleInputSink for SymmetricHashJoinSink {
+    fn sink_left(&mut self, input: &Arc<MicroPartition>) -> DaftResult<SinkResultType> {
+        let matched = Self::probe(&mut self.right, &mut self.left, input, true)?;
+        self.result.push(matched);
+        Ok(SinkResultType::NeedMoreInput)
+    }
+
+    fn sink_right(&mut self, input: &Arc<MicroPartition>) -> DaftResult<SinkResultType> {
+        let matched = Self::probe(&mut self.left, &mut self.right, input, false)?;
+        self.result.push(matched);
+        Ok(SinkResultType::NeedMoreInput)
+    }
+
+    fn in_order(&self) -> bool {
+        false
+    }
+
+    fn finalize(&mut self) -> DaftResult<Vec<Arc<MicroPartition>>> {
+        // Every inner-join match is emitted as its rows stream in, so there is
+        // nothing to flush on close.
+        Ok(std::mem::take(&mut self.result))
+    }
+
+    fn name(&self) -> &'static str {
+        "SymmetricHashJoin"
+    }
+}
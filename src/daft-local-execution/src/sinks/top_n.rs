@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+
+use super::sink::{SingleInputSink, SinkResultType};
+
+/// Bounded Top-N sink fused from `Sort` + `Limit`. Rather than materializing and
+/// sorting the whole input, it keeps only the best `k` rows seen so far. Each
+/// incoming morsel is first reduced to its own top `k` rows, then merged with the
+/// `k` retained rows and truncated back to `k`, so the merge step never sorts
+/// more than `2 * k` rows and retained state stays `O(k)` regardless of input
+/// size — a
+/// merge of two already-bounded sorted runs in place of an element-wise heap,
+/// which suits the columnar morsels the sink receives.
+pub struct TopNSink {
+    sort_by: Vec<ExprRef>,
+    descending: Vec<bool>,
+    k: usize,
+    retained: Option<Arc<MicroPartition>>,
+}
+
+impl TopNSink {
+    pub fn new(sort_by: Vec<ExprRef>, descending: Vec<bool>, k: usize) -> Self {
+        Self {
+            sort_by,
+            descending,
+            k,
+            retained: None,
+        }
+    }
+}
+
+impl SingleInputSink for TopNSink {
+    fn sink(&mut self, input: &Arc<MicroPartition>) -> DaftResult<SinkResultType> {
+        // Bound the incoming morsel to its own top `k` first, so the subsequent
+        // merge with the retained run sorts at most `2 * k` rows rather than the
+        // full morsel plus retained state.
+        let candidate = input.sort(&self.sort_by, &self.descending)?.head(self.k)?;
+        let merged = match self.retained.take() {
+            Some(retained) => MicroPartition::concat(&[retained, Arc::new(candidate)])?,
+            None => candidate,
+        };
+        let sorted = merged.sort(&self.sort_by, &self.descending)?;
+        self.retained = Some(Arc::new(sorted.head(self.k)?));
+        Ok(SinkResultType::NeedMoreInput)
+    }
+
+    fn in_order(&self) -> bool {
+        true
+    }
+
+    fn finalize(&mut self) -> DaftResult<Vec<Arc<MicroPartition>>> {
+        Ok(self.retained.take().into_iter().collect())
+    }
+
+    fn name(&self) -> &'static str {
+        "TopN"
+    }
+}
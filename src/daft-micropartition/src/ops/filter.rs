@@ -0,0 +1,147 @@
+use common_error::DaftResult;
+use daft_dsl::{Expr, ExprRef, LiteralValue, Operator};
+
+use crate::micropartition::MicroPartition;
+
+/// Two-valued verdict for "can this partition contain a matching row?".
+///
+/// Evaluated against a partition's per-column statistics, never against the
+/// data itself. `Maybe` is the conservative fallback for any predicate shape
+/// (or column) we cannot reason about, so pruning is always sound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verdict {
+    /// The predicate is provably false for every row in the partition.
+    False,
+    /// The predicate may hold for some row; the partition must be scanned.
+    Maybe,
+}
+
+impl Verdict {
+    fn and(self, other: Self) -> Self {
+        match (self, other) {
+            (Verdict::False, _) | (_, Verdict::False) => Verdict::False,
+            _ => Verdict::Maybe,
+        }
+    }
+
+    fn or(self, other: Self) -> Self {
+        match (self, other) {
+            (Verdict::Maybe, _) | (_, Verdict::Maybe) => Verdict::Maybe,
+            _ => Verdict::False,
+        }
+    }
+}
+
+impl MicroPartition {
+    /// The sole definition of `filter`, living in `ops/` alongside `take`/`sample`
+    /// rather than duplicated in `micropartition.rs`.
+    pub fn filter(&self, predicate: &ExprRef) -> DaftResult<Self> {
+        // If the statistics rule out every row, skip loading the partition
+        // entirely -- analogous to Parquet row-group pruning.
+        if self.eval_predicate(predicate) == Verdict::False {
+            return Ok(Self::empty(Some(self.schema.clone())));
+        }
+
+        let tables = self.concat_or_get()?;
+        match tables.as_slice() {
+            [] => Ok(Self::empty(Some(self.schema.clone()))),
+            [single] => {
+                let mask = single.eval_expression(predicate)?;
+                let filtered = single.mask_filter(&mask)?;
+                Ok(Self::from_table(self.schema.clone(), filtered))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Evaluate `predicate` against this partition's column statistics, returning
+    /// [`Verdict::False`] only when no row can possibly match.
+    fn eval_predicate(&self, predicate: &ExprRef) -> Verdict {
+        let Some(statistics) = self.statistics.as_ref() else {
+            return Verdict::Maybe;
+        };
+
+        match predicate.as_ref() {
+            Expr::BinaryOp { op, left, right } => match op {
+                Operator::And => self.eval_predicate(left).and(self.eval_predicate(right)),
+                Operator::Or => self.eval_predicate(left).or(self.eval_predicate(right)),
+                Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq | Operator::Eq => {
+                    eval_comparison(statistics, *op, left, right)
+                }
+                _ => Verdict::Maybe,
+            },
+            _ => Verdict::Maybe,
+        }
+    }
+}
+
+/// Decide whether `left OP right` can possibly hold anywhere in the partition,
+/// using only `statistics`'s per-column `(min, max)` bounds -- never the data.
+///
+/// Only the `column OP literal` / `literal OP column` shapes are understood;
+/// anything else (two columns, a computed expression) falls back to `Maybe`.
+fn eval_comparison(
+    statistics: &crate::micropartition::TableStatistics,
+    op: Operator,
+    left: &ExprRef,
+    right: &ExprRef,
+) -> Verdict {
+    let (column, literal, op) = match (left.as_ref(), right.as_ref()) {
+        (Expr::Column(name), Expr::Literal(lit)) => (name.as_ref(), lit, op),
+        (Expr::Literal(lit), Expr::Column(name)) => (name.as_ref(), lit, flip(op)),
+        _ => return Verdict::Maybe,
+    };
+    let Some(value) = literal.as_f64() else {
+        return Verdict::Maybe;
+    };
+    // `min_max` returns the column's known `(min, max)` bounds for the
+    // partition, cast to `f64`, or `None` if the column has no stats yet.
+    let Some((min, max)) = statistics.min_max(column) else {
+        return Verdict::Maybe;
+    };
+
+    // `col OP C` can only be ruled out if, for every row, `OP` is impossible
+    // given the column's known range `[min, max]`.
+    let possible = match op {
+        Operator::Lt => min < value,
+        Operator::LtEq => min <= value,
+        Operator::Gt => max > value,
+        Operator::GtEq => max >= value,
+        Operator::Eq => min <= value && value <= max,
+        _ => return Verdict::Maybe,
+    };
+
+    if possible {
+        Verdict::Maybe
+    } else {
+        Verdict::False
+    }
+}
+
+/// Flip a comparison operator to swap the sides of `a OP b` into `b OP' a`.
+fn flip(op: Operator) -> Operator {
+    match op {
+        Operator::Lt => Operator::Gt,
+        Operator::LtEq => Operator::GtEq,
+        Operator::Gt => Operator::Lt,
+        Operator::GtEq => Operator::LtEq,
+        other => other,
+    }
+}
+
+trait LiteralAsF64 {
+    fn as_f64(&self) -> Option<f64>;
+}
+
+impl LiteralAsF64 for LiteralValue {
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            LiteralValue::Int32(v) => Some(*v as f64),
+            LiteralValue::UInt32(v) => Some(*v as f64),
+            LiteralValue::Int64(v) => Some(*v as f64),
+            LiteralValue::UInt64(v) => Some(*v as f64),
+            LiteralValue::Float64(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
@@ -3,13 +3,18 @@ use std::sync::Arc;
 use crate::logical_plan::LogicalPlan;
 use crate::planner::PhysicalPlanner;
 use crate::source_info::{FileInfo, PyFileFormatConfig, SourceInfo};
-use crate::{ops, PartitionScheme, PartitionSpec};
+use crate::{ops, JoinType, PartitionScheme, PartitionSpec};
 
 #[cfg(feature = "python")]
 use {
+    common_error::{DaftError, DaftResult},
+    common_treenode::{Transformed, TreeNode},
     daft_core::python::schema::PySchema,
+    daft_core::utils::supertype::try_get_supertype,
     daft_dsl::python::PyExpr,
-    pyo3::{prelude::*, types::PyList},
+    daft_dsl::{col, Expr, ExprRef},
+    pyo3::{exceptions::PyValueError, prelude::*, types::{PyBytes, PyList}},
+    std::collections::HashSet,
 };
 
 #[cfg_attr(feature = "python", pyclass)]
@@ -64,8 +69,139 @@ impl LogicalPlanBuilder {
         Ok(logical_plan_builder)
     }
 
-    pub fn aggregate(&self, aggregates: &PyList) -> PyResult<LogicalPlanBuilder> {
-        todo!()
+    pub fn join(
+        &self,
+        right: &LogicalPlanBuilder,
+        left_on: &PyList,
+        right_on: &PyList,
+        how: JoinType,
+    ) -> PyResult<LogicalPlanBuilder> {
+        let left_on = pyexprs_to_exprs(left_on)?;
+        let right_on = pyexprs_to_exprs(right_on)?;
+
+        // Resolve the join keys against each side up front: matching arity and a
+        // common supertype per key pair are what let the hash-join hash the two
+        // sides into the same buckets. `to_field` also surfaces unknown columns
+        // here, and with both inputs resolved against their own schema a later
+        // identically named column on the other side can no longer shadow a key.
+        if left_on.len() != right_on.len() {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Join requires an equal number of left and right keys, got {} and {}",
+                left_on.len(),
+                right_on.len()
+            ))
+            .into());
+        }
+        let left_schema = self.plan.schema();
+        let right_schema = right.plan.schema();
+        for (l, r) in left_on.iter().zip(right_on.iter()) {
+            let lf = l.to_field(&left_schema)?;
+            let rf = r.to_field(&right_schema)?;
+            try_get_supertype(&lf.dtype, &rf.dtype).map_err(|_| {
+                DaftError::SchemaMismatch(format!(
+                    "Join keys `{}` and `{}` have no common type to compare on: {:?} vs {:?}",
+                    lf.name, rf.name, lf.dtype, rf.dtype
+                ))
+            })?;
+        }
+
+        // A column present under the same name on both sides would otherwise
+        // collide in the joined output (and in `left_on`/`right_on`, if it's a
+        // join key referenced from both sides). Qualify those columns with a
+        // `left.`/`right.` prefix on whichever side they came from, so the
+        // joined schema disambiguates them the same way `left.id` vs `right.id`
+        // would read in SQL. Columns unique to one side are left alone.
+        let overlapping: HashSet<&str> = left_schema
+            .fields
+            .keys()
+            .map(String::as_str)
+            .filter(|name| right_schema.fields.contains_key(*name))
+            .collect();
+
+        let (left_plan, left_on) =
+            qualify_side(self.plan.clone(), &left_schema, &overlapping, "left", left_on)?;
+        let (right_plan, right_on) = qualify_side(
+            right.plan.clone(),
+            &right_schema,
+            &overlapping,
+            "right",
+            right_on,
+        )?;
+
+        // `ops::Join` builds the physical hash-join (hash the smaller side's
+        // key columns, probe the larger side, gather matches via
+        // `MicroPartition::take`) once `PhysicalPlanner` lowers this node.
+        let logical_plan: LogicalPlan =
+            ops::Join::new(left_plan, right_plan, left_on, right_on, how)?.into();
+        let logical_plan_builder = LogicalPlanBuilder::new(logical_plan.into());
+        Ok(logical_plan_builder)
+    }
+
+    pub fn aggregate(
+        &self,
+        aggregates: &PyList,
+        group_by: &PyList,
+    ) -> PyResult<LogicalPlanBuilder> {
+        let aggregates = pyexprs_to_exprs(aggregates)?;
+        let group_by = pyexprs_to_exprs(group_by)?;
+
+        // Validate every aggregate's input type against the source schema the same
+        // way the `Series` grouped kernels do, so e.g. `sum` over a non-numeric
+        // column is rejected at plan construction rather than mid-execution.
+        // `to_field` on an `Expr::Agg` runs that per-aggregate type check.
+        let input_schema = self.plan.schema();
+        for agg in &aggregates {
+            agg.to_field(&input_schema)?;
+        }
+
+        let logical_plan: LogicalPlan =
+            ops::Aggregate::new(self.plan.clone(), aggregates, group_by)?.into();
+        let logical_plan_builder = LogicalPlanBuilder::new(logical_plan.into());
+        Ok(logical_plan_builder)
+    }
+
+    pub fn union(&self, other: &LogicalPlanBuilder, all: bool) -> PyResult<LogicalPlanBuilder> {
+        let left_schema = self.plan.schema();
+        let right_schema = other.plan.schema();
+        if left_schema.fields.len() != right_schema.fields.len() {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Cannot union plans with {} and {} columns",
+                left_schema.fields.len(),
+                right_schema.fields.len()
+            ))
+            .into());
+        }
+
+        // Pair columns positionally, widen each pair to its supertype (reusing the
+        // numeric-widening rules the `Series` kernels apply), and insert the
+        // implicit casts into each side so both conform before concatenation. The
+        // output column names follow the left side, matching SQL `UNION`.
+        let mut left_casts: Vec<ExprRef> = Vec::with_capacity(left_schema.fields.len());
+        let mut right_casts: Vec<ExprRef> = Vec::with_capacity(right_schema.fields.len());
+        for (left_field, right_field) in left_schema.fields.values().zip(right_schema.fields.values())
+        {
+            let supertype =
+                try_get_supertype(&left_field.dtype, &right_field.dtype).map_err(|_| {
+                    DaftError::SchemaMismatch(format!(
+                        "Cannot union columns `{}` ({:?}) and `{}` ({:?}): no common supertype",
+                        left_field.name, left_field.dtype, right_field.name, right_field.dtype
+                    ))
+                })?;
+            left_casts.push(col(left_field.name.as_str()).cast(&supertype));
+            right_casts
+                .push(col(right_field.name.as_str()).cast(&supertype).alias(left_field.name.as_str()));
+        }
+
+        let left: Arc<LogicalPlan> =
+            Arc::new(ops::Project::new(self.plan.clone(), left_casts)?.into());
+        let right: Arc<LogicalPlan> =
+            Arc::new(ops::Project::new(other.plan.clone(), right_casts)?.into());
+
+        // `Union::new` concatenates the now-conforming inputs; `all = false` layers
+        // a distinct on top to deduplicate rows.
+        let logical_plan: LogicalPlan = ops::Union::new(left, right, all)?.into();
+        let logical_plan_builder = LogicalPlanBuilder::new(logical_plan.into());
+        Ok(logical_plan_builder)
     }
 
     pub fn schema(&self) -> PyResult<PySchema> {
@@ -85,4 +221,146 @@ impl LogicalPlanBuilder {
     pub fn repr_ascii(&self) -> PyResult<String> {
         Ok(self.plan.repr_ascii())
     }
+
+    pub fn to_bytes(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = serialize_plan(self.plan.as_ref())
+            .map_err(|e| PyValueError::new_err(format!("Failed to serialize plan: {e}")))?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(bytes: &[u8]) -> PyResult<LogicalPlanBuilder> {
+        let plan = deserialize_plan(bytes)
+            .map_err(|e| PyValueError::new_err(format!("Failed to deserialize plan: {e}")))?;
+        Ok(LogicalPlanBuilder::new(plan.into()))
+    }
+}
+
+/// Wire-format version for serialized [`LogicalPlan`] messages. `bincode` is a
+/// positional encoding, so the whole tree (source info, filters, limits, and the
+/// embedded `FunctionExpr`/`ExprRef` trees, all of which already derive
+/// `Serialize`/`Deserialize`) is keyed by field order rather than by name. The
+/// leading version byte lets workers reject a payload written by an incompatible
+/// coordinator instead of silently misreading reordered fields; bump it whenever
+/// an on-wire struct changes shape.
+const PLAN_WIRE_VERSION: u8 = 1;
+
+/// Encode a plan as `[version, bincode(plan)]`.
+pub fn serialize_plan(plan: &LogicalPlan) -> Result<Vec<u8>, bincode::Error> {
+    let mut bytes = Vec::with_capacity(1 + 64);
+    bytes.push(PLAN_WIRE_VERSION);
+    bincode::serialize_into(&mut bytes, plan)?;
+    Ok(bytes)
+}
+
+/// Decode a plan, rejecting payloads whose version byte does not match.
+pub fn deserialize_plan(bytes: &[u8]) -> Result<LogicalPlan, bincode::Error> {
+    match bytes.split_first() {
+        Some((&PLAN_WIRE_VERSION, rest)) => bincode::deserialize(rest),
+        Some((&other, _)) => Err(bincode::Error::new(bincode::ErrorKind::Custom(format!(
+            "unsupported plan wire version {other}, expected {PLAN_WIRE_VERSION}"
+        )))),
+        None => Err(bincode::Error::new(bincode::ErrorKind::Custom(
+            "empty plan payload".to_string(),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A plan survives `to_bytes` → `from_bytes` unchanged. Re-encoding the
+    /// decoded plan and comparing the bytes asserts structural equality without
+    /// requiring `LogicalPlan: PartialEq`.
+    #[test]
+    fn plan_round_trips_through_bytes() -> Result<(), bincode::Error> {
+        let plan = sample_plan();
+        let encoded = serialize_plan(&plan)?;
+        let decoded = deserialize_plan(&encoded)?;
+        assert_eq!(encoded, serialize_plan(&decoded)?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_foreign_wire_version() {
+        let mut encoded = serialize_plan(&sample_plan()).unwrap();
+        encoded[0] = PLAN_WIRE_VERSION.wrapping_add(1);
+        assert!(deserialize_plan(&encoded).is_err());
+    }
+
+    fn sample_plan() -> LogicalPlan {
+        use crate::source_info::{FileInfo, SourceInfo};
+        use daft_core::{datatypes::Field, schema::Schema, DataType};
+
+        let schema = Arc::new(
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("value", DataType::Float64),
+            ])
+            .unwrap(),
+        );
+        let source_info = SourceInfo::new(
+            schema.clone(),
+            FileInfo::new(vec!["a.parquet".to_string()], None, None, None),
+            Default::default(),
+        );
+        let partition_spec = PartitionSpec::new(PartitionScheme::Unknown, 1, None);
+        ops::Source::new(schema, source_info.into(), partition_spec.into()).into()
+    }
+}
+
+#[cfg(feature = "python")]
+fn pyexprs_to_exprs(exprs: &PyList) -> PyResult<Vec<ExprRef>> {
+    exprs
+        .iter()
+        .map(|e| Ok(e.extract::<PyExpr>()?.expr.clone()))
+        .collect()
+}
+
+/// If any of `schema`'s fields are in `overlapping`, wrap `plan` in an
+/// `ops::Project` that renames those fields to `"{prefix}.{name}"` and rewrite
+/// `keys` to reference the new names, so the keys stay resolvable against the
+/// renamed plan. Returns `plan`/`keys` unchanged when nothing overlaps.
+#[cfg(feature = "python")]
+fn qualify_side(
+    plan: Arc<LogicalPlan>,
+    schema: &daft_core::schema::Schema,
+    overlapping: &HashSet<&str>,
+    prefix: &str,
+    keys: Vec<ExprRef>,
+) -> PyResult<(Arc<LogicalPlan>, Vec<ExprRef>)> {
+    if overlapping.is_empty() {
+        return Ok((plan, keys));
+    }
+
+    let projection: Vec<ExprRef> = schema
+        .fields
+        .values()
+        .map(|field| {
+            let column = col(field.name.as_str());
+            if overlapping.contains(field.name.as_str()) {
+                column.alias(format!("{prefix}.{}", field.name).as_str())
+            } else {
+                column
+            }
+        })
+        .collect();
+    let plan: Arc<LogicalPlan> = Arc::new(ops::Project::new(plan, projection)?.into());
+
+    let keys = keys
+        .into_iter()
+        .map(|key| {
+            key.transform_down(&|node: ExprRef| match node.as_ref() {
+                Expr::Column(name) if overlapping.contains(name.as_ref()) => Ok(Transformed::yes(
+                    col(format!("{prefix}.{name}").as_str()),
+                )),
+                _ => Ok(Transformed::no(node)),
+            })
+            .map(|t| t.data)
+        })
+        .collect::<DaftResult<Vec<_>>>()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    Ok((plan, keys))
 }